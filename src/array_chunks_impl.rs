@@ -1,7 +1,11 @@
 //! Actual implementation of the iterator. This module should be kept as small as possible to
 //! minimize the amount of code that could possibly violate this type's invariants and cause UB
 
-use core::mem::MaybeUninit;
+use core::array;
+use core::convert::Infallible;
+use core::iter::FusedIterator;
+use core::mem::{self, MaybeUninit};
+use core::ops::Try;
 
 /// Iterator adapter like [`slice::array_chunks`] but for any iterator
 #[derive(Debug)]
@@ -28,6 +32,63 @@ impl<I, T, const N: usize> ArrayChunks<I, T, N> {
         // state at any point in time
         unsafe { MaybeUninit::slice_assume_init_ref(&self.buf[..self.num_init]) }
     }
+
+    /// Like [`Self::remainder`], but consumes `self` and returns the leftover items as an owning
+    /// iterator instead of a borrowed slice
+    pub fn into_remainder(self) -> array::IntoIter<T, N> {
+        // SAFETY: `this` is never used again after `buf` is read out and `iter` is dropped below,
+        // so the fact that its own `Drop` impl no longer runs is fine
+        let mut this = mem::ManuallyDrop::new(self);
+        let buf = unsafe { core::ptr::read(&this.buf) };
+        let num_init = this.num_init;
+        // SAFETY: `this.iter` is not read again afterwards; this still runs its destructor,
+        // unlike forgetting the whole `ArrayChunks`, which would leak the source iterator
+        unsafe { core::ptr::drop_in_place(&mut this.iter) };
+        // SAFETY: buf[..num_init] is initialized, per the same invariant `remainder()` relies on
+        unsafe { array::IntoIter::new_unchecked(buf, 0..num_init) }
+    }
+
+    /// Attempts to pull the next `N` items from the underlying iterator into a chunk.
+    ///
+    /// On success, returns `Ok([T; N])`, exactly like a call to [`Iterator::next`] would.
+    /// Unlike [`Iterator::next`], if the source iterator runs dry early, the items that were
+    /// already pulled are not discarded: they are handed back as `Err(IntoIter)`. Since this
+    /// only ever calls the source's `next()` at most `N` times, the source iterator is left
+    /// perfectly usable afterwards.
+    pub fn next_chunk(&mut self) -> Result<[T; N], array::IntoIter<T, N>>
+    where
+        I: Iterator<Item = T>,
+    {
+        // SAFETY: self.num_init can never be `> self.buf.len()`: every place that increments
+        // it (this loop, `next()` and `try_fold()`) resets it to 0 once it reaches `N`
+        for slot in unsafe { self.buf.get_unchecked_mut(self.num_init..) } {
+            match self.iter.next() {
+                Some(item) => {
+                    *slot = MaybeUninit::new(item);
+                    self.num_init += 1;
+                }
+                None => {
+                    // SAFETY: buf[..num_init] is initialized, same invariant `remainder()`
+                    // relies on; num_init is reset right below so those items are never
+                    // dropped twice
+                    let leftover = unsafe {
+                        array::IntoIter::new_unchecked(core::ptr::read(&self.buf), 0..self.num_init)
+                    };
+                    self.num_init = 0;
+                    return Err(leftover);
+                }
+            }
+        }
+        // SAFETY: array_assume_init: at this point, we have completely iterated through
+        // self.buf and set each item to an instance of MaybeUninit::new(). Therefore, the
+        // entire array is in an initialized state, as array_assume_init requires.
+        // SAFETY: std::ptr::read: self.num_init is set to zero immediately after this, so the
+        // items from buf we're cloning out will never be read again. Therefore, those items
+        // won't be duplicated.
+        let chunk = unsafe { MaybeUninit::array_assume_init(core::ptr::read(&self.buf)) };
+        self.num_init = 0;
+        Ok(chunk)
+    }
 }
 
 impl<I, T, const N: usize> Iterator for ArrayChunks<I, T, N>
@@ -61,6 +122,174 @@ where
             max_items.and_then(|max_items| Some(max_items.checked_add(self.num_init)? / N));
         (min_chunks, max_chunks)
     }
+
+    fn try_fold<B, F, R>(&mut self, init: B, mut f: F) -> R
+    where
+        Self: Sized,
+        F: FnMut(B, Self::Item) -> R,
+        R: Try<Output = B>,
+    {
+        let Self { iter, buf, num_init } = self;
+        // in case `iter.try_fold` or `f` below panics, this guard makes sure the elements
+        // already written into `buf[..*num_init]` still get dropped instead of leaking
+        let guard = Guard {
+            buf,
+            len: num_init,
+        };
+
+        let result = iter.try_fold(init, |acc, item| {
+            // SAFETY: `*guard.len` is always `< N` here, since it is reset to 0 right after
+            // reaching `N` below
+            unsafe { guard.buf.get_unchecked_mut(*guard.len) }.write(item);
+            *guard.len += 1;
+
+            if *guard.len == N {
+                *guard.len = 0;
+                // SAFETY: we just wrote to every slot of `guard.buf`
+                let chunk = unsafe { MaybeUninit::array_assume_init(core::ptr::read(guard.buf)) };
+                f(acc, chunk)
+            } else {
+                R::from_output(acc)
+            }
+        });
+
+        // whatever state `guard.len` was left in above (a finished chunk resets it to 0, a
+        // panic would unwind through the `Drop` below instead) is exactly the state
+        // `self.num_init` should be left in, so there is nothing left to fold back
+        mem::forget(guard);
+
+        result
+    }
+
+    fn fold<B, F>(self, init: B, f: F) -> B
+    where
+        F: FnMut(B, Self::Item) -> B,
+    {
+        if self.num_init == 0 {
+            // SAFETY: self.buf holds nothing initialized (num_init == 0), so there is nothing to
+            // drop or fold back; mem::forget below keeps our own Drop impl from running on the
+            // moved-out-of `self` afterwards
+            let iter = unsafe { core::ptr::read(&self.iter) };
+            mem::forget(self);
+            iter.spec_fold(init, f)
+        } else {
+            self.fold_via_try_fold(init, f)
+        }
+    }
+}
+
+impl<I, T, const N: usize> ArrayChunks<I, T, N>
+where
+    I: Iterator<Item = T>,
+{
+    /// The general fallback for [`Iterator::fold`], implemented in terms of `try_fold` so that
+    /// it also drives chunk assembly through the source's own `try_fold`
+    fn fold_via_try_fold<B, F>(mut self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, [T; N]) -> B,
+    {
+        match self.try_fold(init, |acc, item| Ok::<B, Infallible>(f(acc, item))) {
+            Ok(b) => b,
+            Err(never) => match never {},
+        }
+    }
+}
+
+/// Iterators backed by contiguous, randomly-accessible storage can be indexed directly instead
+/// of being driven through repeated `next()` calls. This is a small stand-in for the standard
+/// library's still-unstable `TrustedRandomAccess` family, scoped to the one source this crate
+/// can soundly special-case without reaching into private standard library state: shared slices.
+///
+/// # Safety
+/// `source_len()` must be the iterator's exact remaining length, and for every `idx <
+/// source_len()`, `get_unchecked(idx)` must yield the same item that the `(idx + 1)`-th call to
+/// `next()` would, without that call actually consuming or otherwise invalidating `self`.
+unsafe trait TrustedIndexedSource: Iterator {
+    fn source_len(&self) -> usize;
+
+    /// # Safety
+    /// `idx` must be `< self.source_len()`.
+    unsafe fn get_unchecked(&self, idx: usize) -> Self::Item;
+}
+
+unsafe impl<'a, T> TrustedIndexedSource for core::slice::Iter<'a, T> {
+    fn source_len(&self) -> usize {
+        self.as_slice().len()
+    }
+
+    unsafe fn get_unchecked(&self, idx: usize) -> Self::Item {
+        // SAFETY: forwarded to the caller of `TrustedIndexedSource::get_unchecked`
+        unsafe { self.as_slice().get_unchecked(idx) }
+    }
+}
+
+/// Gates the [`TrustedIndexedSource`] fast path for [`ArrayChunks::fold`] behind a specialized
+/// impl, so that non-random-access iterators keep going through [`ArrayChunks::fold_via_try_fold`]
+trait SpecFold: Iterator {
+    fn spec_fold<B, F, const N: usize>(self, init: B, f: F) -> B
+    where
+        F: FnMut(B, [Self::Item; N]) -> B;
+}
+
+impl<I: Iterator> SpecFold for I {
+    default fn spec_fold<B, F, const N: usize>(self, init: B, f: F) -> B
+    where
+        F: FnMut(B, [Self::Item; N]) -> B,
+    {
+        ArrayChunks::<Self, Self::Item, N>::new(self).fold_via_try_fold(init, f)
+    }
+}
+
+impl<I: TrustedIndexedSource> SpecFold for I {
+    fn spec_fold<B, F, const N: usize>(self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, [Self::Item; N]) -> B,
+    {
+        let len = self.source_len();
+        let mut acc = init;
+        let mut i = 0;
+        while i + N <= len {
+            // SAFETY: every index in `i..i + N` is `< len == self.source_len()`
+            let chunk = array::from_fn(|j| unsafe { self.get_unchecked(i + j) });
+            acc = f(acc, chunk);
+            i += N;
+        }
+        // the trailing `len % N` items never form a full chunk, so they are never fetched here
+        // at all
+        acc
+    }
+}
+
+impl<I, T, const N: usize> FusedIterator for ArrayChunks<I, T, N> where I: FusedIterator<Item = T> {}
+
+impl<I, T, const N: usize> ExactSizeIterator for ArrayChunks<I, T, N>
+where
+    I: ExactSizeIterator<Item = T>,
+{
+    fn len(&self) -> usize {
+        (self.iter.len() + self.num_init) / N
+    }
+}
+
+/// Drops `buf[..*len]` when dropped. Used by [`ArrayChunks::try_fold`] to avoid leaking the
+/// elements of an in-progress chunk if the source iterator or the user-provided closure panics
+struct Guard<'a, T, const N: usize> {
+    buf: &'a mut [MaybeUninit<T>; N],
+    len: &'a mut usize,
+}
+
+impl<T, const N: usize> Drop for Guard<'_, T, N> {
+    fn drop(&mut self) {
+        for item in &mut self.buf[..*self.len] {
+            // SAFETY: the Iterator::try_fold() implementation ensures buf[..len] is in an
+            // initialized state at any point in time
+            unsafe { item.assume_init_drop() };
+        }
+        // `len` aliases the owning `ArrayChunks`'s `num_init`; resetting it here keeps that
+        // invariant intact through the rest of the unwind, so the owner's own `Drop` impl does
+        // not try to drop these same (already-dropped) elements a second time
+        *self.len = 0;
+    }
 }
 
 impl<I, T, const N: usize> Clone for ArrayChunks<I, T, N>